@@ -0,0 +1,81 @@
+//! Benchmarking setup for pallet-template
+
+use super::*;
+#[allow(unused)]
+use crate::Pallet as Template;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+// `instance` pins the generated `impl_benchmark_test_suite!` below to this pallet's first
+// instance (`Instance1`) so it has a single, unambiguous `Config` impl to benchmark against —
+// `Test` implements `Config<Instance1>` and `Config<Instance2>` but neither implements the
+// unparameterized default `Config<()>` that a plain `where T: Config<I>, I: 'static` suite would
+// reach for.
+#[benchmarks(instance)]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn set_single_entry() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), 1u32);
+
+        assert_eq!(SimpleMap::<T, I>::get(caller), 1);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn get_single_entry() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let target: T::AccountId = account("target", 0, 0);
+        SimpleMap::<T, I>::insert(&target, 1u32);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), target);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn take_single_entry() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        SimpleMap::<T, I>::insert(&caller, 1u32);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert!(!SimpleMap::<T, I>::contains_key(caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn increase_single_entry() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        SimpleMap::<T, I>::insert(&caller, 1u32);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), 1u32);
+
+        assert_eq!(SimpleMap::<T, I>::get(caller), 2);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn record_offchain_aggregate() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 42u32);
+
+        assert_eq!(OffchainAggregate::<T, I>::get(), Some(42));
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(
+        Template,
+        crate::mock::new_test_ext(),
+        crate::mock::Test,
+    );
+}
@@ -22,18 +22,51 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+/// The key type under which this pallet's offchain worker stores its signing keys, so a node
+/// operator can inject one with `author_insertKey` using this pallet's crypto.
+pub const KEY_TYPE: sp_runtime::KeyTypeId = sp_runtime::KeyTypeId(*b"tmpl");
+
+/// Signing keys and crypto used by this pallet's offchain worker to submit
+/// `record_offchain_aggregate` as a signed transaction.
+pub mod crypto {
+    use super::KEY_TYPE;
+    use sp_runtime::{
+        app_crypto::{app_crypto, sr25519},
+        traits::Verify,
+    };
+
+    app_crypto!(sr25519, KEY_TYPE);
+
+    type Sr25519Signature = sp_core::sr25519::Signature;
+
+    pub struct TemplateAuthId;
+
+    impl frame_system::offchain::AppCrypto<<Sr25519Signature as Verify>::Signer, Sr25519Signature>
+        for TemplateAuthId
+    {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = sp_core::sr25519::Signature;
+        type GenericPublic = sp_core::sr25519::Public;
+    }
+}
+
 // All pallet logic is defined in its own module and must be annotated by the `pallet` attribute.
 #[frame_support::pallet(dev_mode)]
 pub mod pallet {
     // Import various useful types required by all FRAME pallets.
     use super::*;
     use frame_support::pallet_prelude::*;
+    use frame_system::offchain::{
+        AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer,
+    };
     use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::Zero;
 
     // The `Pallet` struct serves as a placeholder to implement traits, methods and dispatchables
-    // (`Call`s) in this pallet.
+    // (`Call`s) in this pallet. The `I` generic lets a runtime deploy more than one independent
+    // instance of this pallet, each with its own storage.
     #[pallet::pallet]
-    pub struct Pallet<T>(_);
+    pub struct Pallet<T, I = ()>(_);
 
     /// The pallet's configuration trait.
     ///
@@ -41,18 +74,34 @@ pub mod pallet {
     /// These types are defined generically and made concrete when the pallet is declared in the
     /// `runtime/src/lib.rs` file of your chain.
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config<I: 'static = ()>:
+        frame_system::Config + CreateSignedTransaction<Call<Self, I>>
+    {
         /// The overarching runtime event type.
-        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type RuntimeEvent: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         /// A type representing the weights required by the dispatchables of this pallet.
         type WeightInfo: WeightInfo;
+
+        /// The identifier type for the offchain worker's signing key, used to submit
+        /// `record_offchain_aggregate` as a signed transaction.
+        type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+        /// How often, in blocks, the offchain worker recomputes and submits the aggregate.
+        #[pallet::constant]
+        type AggregationInterval: Get<BlockNumberFor<Self>>;
     }
 
     #[pallet::storage]
     #[pallet::getter(fn simple_map)]
-    pub(super) type SimpleMap<T: Config> =
+    pub(super) type SimpleMap<T: Config<I>, I: 'static = ()> =
         StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
 
+    /// The most recent aggregate over `SimpleMap`'s entries, as last submitted by the offchain
+    /// worker via [`Pallet::record_offchain_aggregate`].
+    #[pallet::storage]
+    #[pallet::getter(fn offchain_aggregate)]
+    pub(super) type OffchainAggregate<T: Config<I>, I: 'static = ()> = StorageValue<_, u32>;
+
     /// Events that functions in this pallet can emit.
     ///
     /// Events are a simple means of indicating to the outside world (such as dApps, chain explorers
@@ -65,7 +114,7 @@ pub mod pallet {
     /// [`Config`] trait) and deposit it using [`frame_system::Pallet::deposit_event`].
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
+    pub enum Event<T: Config<I>, I: 'static = ()> {
         /// A user has set their entry
         EntrySet(T::AccountId, u32),
 
@@ -78,6 +127,9 @@ pub mod pallet {
         /// A user has read their entry, incremented it, and written the new entry to storage
         /// Parameters are (user, old_entry, new_entry)
         EntryIncreased(T::AccountId, u32, u32),
+
+        /// The offchain worker submitted a fresh aggregate over `SimpleMap`'s entries
+        OffchainAggregateRecorded(u32),
     }
 
     /// Errors that can be returned by this pallet.
@@ -89,7 +141,7 @@ pub mod pallet {
     /// This type of runtime error can be up to 4 bytes in size should you want to return additional
     /// information.
     #[pallet::error]
-    pub enum Error<T> {
+    pub enum Error<T, I = ()> {
         /// The requested user has not stored a value yet
         NoValueStored,
 
@@ -110,14 +162,14 @@ pub mod pallet {
     ///
     /// The [`weight`] macro is used to assign a weight to each call.
     #[pallet::call]
-    impl<T: Config> Pallet<T> {
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
         #[pallet::call_index(0)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::set_single_entry())]
         pub fn set_single_entry(origin: OriginFor<T>, entry: u32) -> DispatchResultWithPostInfo {
             // A user can only set their own entry
             let user = ensure_signed(origin)?;
 
-            <SimpleMap<T>>::insert(&user, entry);
+            <SimpleMap<T, I>>::insert(&user, entry);
 
             Self::deposit_event(Event::EntrySet(user, entry));
             Ok(().into())
@@ -125,7 +177,7 @@ pub mod pallet {
 
         /// Read the value stored at a particular key and emit it in an event
         #[pallet::call_index(1)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::get_single_entry())]
         pub fn get_single_entry(
             origin: OriginFor<T>,
             account: T::AccountId,
@@ -134,10 +186,10 @@ pub mod pallet {
             let getter = ensure_signed(origin)?;
 
             ensure!(
-                <SimpleMap<T>>::contains_key(&account),
-                Error::<T>::NoValueStored
+                <SimpleMap<T, I>>::contains_key(&account),
+                Error::<T, I>::NoValueStored
             );
-            let entry = <SimpleMap<T>>::get(account);
+            let entry = <SimpleMap<T, I>>::get(account);
             Self::deposit_event(Event::EntryGot(getter, entry));
             Ok(().into())
         }
@@ -145,23 +197,23 @@ pub mod pallet {
         /// Read the value stored at a particular key, while removing it from the map.
         /// Also emit the read value in an event
         #[pallet::call_index(2)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::take_single_entry())]
         pub fn take_single_entry(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             // A user can only take (delete) their own entry
             let user = ensure_signed(origin)?;
 
             ensure!(
-                <SimpleMap<T>>::contains_key(&user),
-                Error::<T>::NoValueStored
+                <SimpleMap<T, I>>::contains_key(&user),
+                Error::<T, I>::NoValueStored
             );
-            let entry = <SimpleMap<T>>::take(&user);
+            let entry = <SimpleMap<T, I>>::take(&user);
             Self::deposit_event(Event::EntryTaken(user, entry));
             Ok(().into())
         }
 
         /// Increase the value associated with a particular key
         #[pallet::call_index(3)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::increase_single_entry())]
         pub fn increase_single_entry(
             origin: OriginFor<T>,
             add_this_val: u32,
@@ -170,19 +222,79 @@ pub mod pallet {
             let user = ensure_signed(origin)?;
 
             ensure!(
-                <SimpleMap<T>>::contains_key(&user),
-                Error::<T>::NoValueStored
+                <SimpleMap<T, I>>::contains_key(&user),
+                Error::<T, I>::NoValueStored
             );
-            let original_value = <SimpleMap<T>>::get(&user);
+            let original_value = <SimpleMap<T, I>>::get(&user);
 
             let new_value = original_value
                 .checked_add(add_this_val)
-                .ok_or(Error::<T>::MaxValueReached)?;
-            <SimpleMap<T>>::insert(&user, new_value);
+                .ok_or(Error::<T, I>::MaxValueReached)?;
+            <SimpleMap<T, I>>::insert(&user, new_value);
 
             Self::deposit_event(Event::EntryIncreased(user, original_value, new_value));
 
             Ok(().into())
         }
+
+        /// Record a fresh aggregate over `SimpleMap`'s entries, as computed and signed by the
+        /// offchain worker in [`Hooks::offchain_worker`].
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::record_offchain_aggregate())]
+        pub fn record_offchain_aggregate(
+            origin: OriginFor<T>,
+            aggregate: u32,
+        ) -> DispatchResultWithPostInfo {
+            let _ = ensure_signed(origin)?;
+
+            <OffchainAggregate<T, I>>::put(aggregate);
+            Self::deposit_event(Event::OffchainAggregateRecorded(aggregate));
+
+            Ok(().into())
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+        /// Every `AggregationInterval` blocks, sum `SimpleMap`'s entries and submit the result
+        /// back on chain as a signed `record_offchain_aggregate` transaction.
+        fn offchain_worker(block_number: BlockNumberFor<T>) {
+            if (block_number % T::AggregationInterval::get()).is_zero() {
+                if let Err(e) = Self::submit_aggregate_signed() {
+                    log::error!("simple-map offchain worker failed: {}", e);
+                }
+            }
+        }
+    }
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Sum every entry currently in `SimpleMap` and submit it as a signed
+    /// `record_offchain_aggregate` transaction, using whichever local accounts have this
+    /// pallet's offchain signing key injected.
+    fn submit_aggregate_signed() -> Result<(), &'static str> {
+        use frame_system::offchain::{SendSignedTransaction, Signer};
+
+        let signer = Signer::<T, T::AuthorityId>::all_accounts();
+        if !signer.can_sign() {
+            return Err(
+                "No local accounts available to sign the offchain aggregate transaction",
+            );
+        }
+
+        let aggregate =
+            <SimpleMap<T, I>>::iter_values().fold(0u32, |acc, v| acc.saturating_add(v));
+
+        let results = signer.send_signed_transaction(|_account| {
+            Call::<T, I>::record_offchain_aggregate { aggregate }
+        });
+
+        for (_account, result) in &results {
+            if result.is_err() {
+                return Err("Failed to submit the signed offchain aggregate transaction");
+            }
+        }
+
+        Ok(())
     }
 }
@@ -0,0 +1,145 @@
+use crate as pallet_template;
+use crate::crypto::TemplateAuthId;
+use frame_support::derive_impl;
+use frame_support::instances::{Instance1, Instance2};
+use frame_support::traits::ConstU64;
+use sp_core::{
+    offchain::{
+        testing::{TestOffchainExt, TestTransactionPoolExt},
+        OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+    },
+    sr25519::Signature as Sr25519Signature,
+};
+use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
+use sp_runtime::{
+    testing::TestXt,
+    traits::{Extrinsic as ExtrinsicT, IdentifyAccount, Verify},
+    BuildStorage,
+};
+use std::sync::Arc;
+
+/// The mock runtime's account type: derived from the same sr25519 public keys the offchain
+/// worker signs with, so [`new_test_ext_with_offchain_pool`] can inject a signing key and have
+/// it resolve to an account dispatched calls can be checked against.
+pub type AccountId = <<Sr25519Signature as Verify>::Signer as IdentifyAccount>::AccountId;
+
+/// Build an `AccountId` from a single repeated byte, for use in tests as a stand-in for a real
+/// sr25519 public key.
+pub fn account(byte: u8) -> AccountId {
+    sp_core::sr25519::Public::from_raw([byte; 32]).into()
+}
+
+/// The unchecked extrinsic type used to encode signed transactions submitted to the offchain
+/// transaction pool in tests.
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    // Two independent instances of the same recipe pallet, each with its own storage.
+    #[runtime::pallet_index(1)]
+    pub type Template = pallet_template<Instance1>;
+
+    #[runtime::pallet_index(2)]
+    pub type Template2 = pallet_template<Instance2>;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = frame_system::mocking::MockBlock<Test>;
+    type AccountId = AccountId;
+    type Lookup = sp_runtime::traits::IdentityLookup<Self::AccountId>;
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = <Sr25519Signature as Verify>::Signer;
+    type Signature = Sr25519Signature;
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type OverarchingCall = RuntimeCall;
+    type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_transaction<
+        C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>,
+    >(
+        call: RuntimeCall,
+        _public: Self::Public,
+        _account: Self::AccountId,
+        nonce: Self::Nonce,
+    ) -> Option<(RuntimeCall, <Self::Extrinsic as ExtrinsicT>::SignaturePayload)> {
+        Some((call, (nonce, ())))
+    }
+}
+
+impl pallet_template::Config<Instance1> for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type AuthorityId = TemplateAuthId;
+    type AggregationInterval = ConstU64<5>;
+}
+
+impl pallet_template::Config<Instance2> for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type AuthorityId = TemplateAuthId;
+    type AggregationInterval = ConstU64<5>;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    storage.into()
+}
+
+/// Build genesis storage and register offchain worker extensions, so tests can drive
+/// `offchain_worker` and inspect the transactions it submits to the pool.
+///
+/// A signing key under [`crate::KEY_TYPE`] is pre-loaded into the keystore, so
+/// `Signer::all_accounts` has a local account available to sign with.
+pub fn new_test_ext_with_offchain_pool() -> (
+    sp_io::TestExternalities,
+    Arc<parking_lot::RwLock<sp_core::offchain::testing::PoolState>>,
+) {
+    let mut ext = new_test_ext();
+
+    let (offchain, _offchain_state) = TestOffchainExt::new();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    ext.register_extension(OffchainDbExt::new(offchain.clone()));
+    ext.register_extension(OffchainWorkerExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    let keystore = MemoryKeystore::new();
+    keystore
+        .sr25519_generate_new(crate::KEY_TYPE, None)
+        .expect("offchain signing key can be generated");
+    ext.register_extension(KeystoreExt(Arc::new(keystore)));
+
+    (ext, pool_state)
+}
@@ -0,0 +1,109 @@
+use crate::{mock::*, Error};
+use frame_support::instances::Instance1;
+use frame_support::traits::Hooks;
+use frame_support::{assert_noop, assert_ok};
+use parity_scale_codec::Decode;
+
+#[test]
+fn set_single_entry_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Template::set_single_entry(
+            RuntimeOrigin::signed(account(1)),
+            19
+        ));
+        assert_eq!(Template::simple_map(account(1)), 19);
+    });
+}
+
+#[test]
+fn get_single_entry_requires_existing_value() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Template::get_single_entry(RuntimeOrigin::signed(account(2)), account(1)),
+            Error::<Test, Instance1>::NoValueStored
+        );
+
+        assert_ok!(Template::set_single_entry(
+            RuntimeOrigin::signed(account(1)),
+            19
+        ));
+        assert_ok!(Template::get_single_entry(
+            RuntimeOrigin::signed(account(2)),
+            account(1)
+        ));
+    });
+}
+
+#[test]
+fn take_single_entry_removes_value() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Template::set_single_entry(
+            RuntimeOrigin::signed(account(1)),
+            19
+        ));
+        assert_ok!(Template::take_single_entry(RuntimeOrigin::signed(account(
+            1
+        ))));
+        assert_noop!(
+            Template::get_single_entry(RuntimeOrigin::signed(account(2)), account(1)),
+            Error::<Test, Instance1>::NoValueStored
+        );
+    });
+}
+
+#[test]
+fn increase_single_entry_errors_on_overflow() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Template::set_single_entry(
+            RuntimeOrigin::signed(account(1)),
+            u32::MAX
+        ));
+        assert_noop!(
+            Template::increase_single_entry(RuntimeOrigin::signed(account(1)), 1),
+            Error::<Test, Instance1>::MaxValueReached
+        );
+    });
+}
+
+#[test]
+fn instances_have_independent_storage() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Template::set_single_entry(
+            RuntimeOrigin::signed(account(1)),
+            19
+        ));
+        assert_ok!(Template2::set_single_entry(
+            RuntimeOrigin::signed(account(1)),
+            42
+        ));
+
+        assert_eq!(Template::simple_map(account(1)), 19);
+        assert_eq!(Template2::simple_map(account(1)), 42);
+    });
+}
+
+#[test]
+fn offchain_worker_submits_signed_aggregate() {
+    let (mut ext, pool_state) = new_test_ext_with_offchain_pool();
+
+    ext.execute_with(|| {
+        assert_ok!(Template::set_single_entry(
+            RuntimeOrigin::signed(account(1)),
+            19
+        ));
+        assert_ok!(Template::set_single_entry(
+            RuntimeOrigin::signed(account(2)),
+            23
+        ));
+
+        Template::offchain_worker(5);
+
+        let tx = pool_state.write().transactions.pop().unwrap();
+        assert!(pool_state.read().transactions.is_empty());
+        let tx = Extrinsic::decode(&mut &*tx).unwrap();
+        assert_eq!(
+            tx.call,
+            RuntimeCall::Template(crate::Call::record_offchain_aggregate { aggregate: 42 })
+        );
+    });
+}
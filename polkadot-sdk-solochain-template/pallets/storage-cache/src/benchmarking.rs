@@ -0,0 +1,139 @@
+//! Benchmarking setup for pallet-storage-cache
+
+use super::*;
+#[allow(unused)]
+use crate::Pallet as StorageCache;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+// `instance` pins the generated `impl_benchmark_test_suite!` below to this pallet's first
+// instance (`Instance1`) so it has a single, unambiguous `Config` impl to benchmark against —
+// `Test` implements `Config<Instance1>` and `Config<Instance2>` but neither implements the
+// unparameterized default `Config<()>` that a plain `where T: Config<I>, I: 'static` suite would
+// reach for.
+#[benchmarks(instance)]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn increase_value_no_cache() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        SomeCopyValue::<T, I>::put(0u32);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 1u32);
+
+        assert_eq!(SomeCopyValue::<T, I>::get(), Some(1));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn increase_value_w_copy() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        SomeCopyValue::<T, I>::put(0u32);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 1u32);
+
+        assert_eq!(SomeCopyValue::<T, I>::get(), Some(1));
+        Ok(())
+    }
+
+    // `m` is the number of accounts in `GroupMembers` besides the new king when the king swap
+    // happens (`m + 1` members in all); it no
+    // longer changes the asymptotic cost of `is_member` (an `O(1)` map lookup since the
+    // `CountedStorageMap` migration), but it still captures how proof size grows with group size.
+    #[benchmark]
+    fn swap_king_no_cache(m: Linear<1, { T::MaxMembers::get() }>) -> Result<(), BenchmarkError> {
+        let existing_king: T::AccountId = account("existing_king", 0, 0);
+        KingMember::<T, I>::put(existing_king);
+
+        let new_king: T::AccountId = whitelisted_caller();
+        for i in 0..m {
+            let member: T::AccountId = account("member", i, 0);
+            GroupMembers::<T, I>::insert(member, ());
+        }
+        GroupMembers::<T, I>::insert(new_king.clone(), ());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(new_king.clone()));
+
+        assert_eq!(KingMember::<T, I>::get(), Some(new_king));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn swap_king_with_cache(m: Linear<1, { T::MaxMembers::get() }>) -> Result<(), BenchmarkError> {
+        let existing_king: T::AccountId = account("existing_king", 0, 0);
+        KingMember::<T, I>::put(existing_king);
+
+        let new_king: T::AccountId = whitelisted_caller();
+        for i in 0..m {
+            let member: T::AccountId = account("member", i, 0);
+            GroupMembers::<T, I>::insert(member, ());
+        }
+        GroupMembers::<T, I>::insert(new_king.clone(), ());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(new_king.clone()));
+
+        assert_eq!(KingMember::<T, I>::get(), Some(new_king));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_copy() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 1u32);
+
+        assert_eq!(SomeCopyValue::<T, I>::get(), Some(1));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_king() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert_eq!(KingMember::<T, I>::get(), Some(caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn mock_add_member(m: Linear<1, { T::MaxMembers::get() - 1 }>) -> Result<(), BenchmarkError> {
+        for i in 0..m {
+            let member: T::AccountId = account("member", i, 0);
+            GroupMembers::<T, I>::insert(member, ());
+        }
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert!(Pallet::<T, I>::is_member(&caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn dispatch_as_king() -> Result<(), BenchmarkError> {
+        let king: T::AccountId = whitelisted_caller();
+        KingMember::<T, I>::put(king.clone());
+        let call: Box<<T as Config<I>>::RuntimeCall> =
+            Box::new(Call::<T, I>::set_copy { val: 1u32 }.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(king), call);
+
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(
+        StorageCache,
+        crate::mock::new_test_ext(),
+        crate::mock::Test,
+    );
+}
@@ -27,13 +27,15 @@ pub use weights::*;
 pub mod pallet {
     // Import various useful types required by all FRAME pallets.
     use super::*;
+    use frame_support::dispatch::{Dispatchable, GetDispatchInfo};
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
 
     // The `Pallet` struct serves as a placeholder to implement traits, methods and dispatchables
-    // (`Call`s) in this pallet.
+    // (`Call`s) in this pallet. The `I` generic lets a runtime deploy more than one independent
+    // instance of this pallet, each with its own storage.
     #[pallet::pallet]
-    pub struct Pallet<T>(_);
+    pub struct Pallet<T, I = ()>(_);
 
     /// The pallet's configuration trait.
     ///
@@ -41,24 +43,72 @@ pub mod pallet {
     /// These types are defined generically and made concrete when the pallet is declared in the
     /// `runtime/src/lib.rs` file of your chain.
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config<I: 'static = ()>: frame_system::Config {
         /// The overarching runtime event type.
-        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type RuntimeEvent: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         /// A type representing the weights required by the dispatchables of this pallet.
         type WeightInfo: WeightInfo;
+
+        /// The aggregated origin which the dispatchables of this pallet can be called under, and
+        /// which this pallet's own [`Origin`] can be converted into. Needed so
+        /// [`Pallet::dispatch_as_king`] can re-dispatch a call under the [`Origin::King`] origin.
+        type RuntimeOrigin: From<<Self as frame_system::Config>::RuntimeOrigin>
+            + From<Origin<Self, I>>
+            + Into<Result<Origin<Self, I>, <Self as Config<I>>::RuntimeOrigin>>;
+
+        /// The aggregated call type, so that [`Pallet::dispatch_as_king`] can re-dispatch an
+        /// inner call under the king's origin.
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = <Self as Config<I>>::RuntimeOrigin>
+            + GetDispatchInfo
+            + From<Call<Self, I>>;
+
+        /// The maximum number of accounts `GroupMembers` may hold at once. Bounds the cost of
+        /// [`Pallet::mock_add_member`] and keeps the weight of anything that checks membership
+        /// provably bounded.
+        #[pallet::constant]
+        type MaxMembers: Get<u32>;
+    }
+
+    /// A custom origin, granted exclusively to the account currently stored in [`KingMember`].
+    /// Dispatching as this origin (via [`Pallet::dispatch_as_king`]) lets the king act with a
+    /// privilege level distinct from a plain signed account, mirroring how `pallet_utility`'s
+    /// `dispatch_as` re-dispatches a call under a chosen origin.
+    #[pallet::origin]
+    #[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub enum Origin<T: Config<I>, I: 'static = ()> {
+        /// It has been condoned by the king themselves.
+        King(T::AccountId),
+    }
+
+    /// Ensures that the origin is the [`Origin::King`] origin.
+    pub struct EnsureKing<T, I = ()>(PhantomData<(T, I)>);
+    impl<T: Config<I>, I: 'static> EnsureOrigin<<T as Config<I>>::RuntimeOrigin> for EnsureKing<T, I> {
+        type Success = T::AccountId;
+
+        fn try_origin(
+            o: <T as Config<I>>::RuntimeOrigin,
+        ) -> Result<Self::Success, <T as Config<I>>::RuntimeOrigin> {
+            o.into().map(|o| match o {
+                Origin::King(who) => who,
+            })
+        }
     }
 
     #[pallet::storage]
     #[pallet::getter(fn some_copy_value)]
-    pub(super) type SomeCopyValue<T: Config> = StorageValue<_, u32>;
+    pub(super) type SomeCopyValue<T: Config<I>, I: 'static = ()> = StorageValue<_, u32>;
 
     #[pallet::storage]
     #[pallet::getter(fn king_member)]
-    pub(super) type KingMember<T: Config> = StorageValue<_, T::AccountId>;
+    pub(super) type KingMember<T: Config<I>, I: 'static = ()> = StorageValue<_, T::AccountId>;
 
+    /// The group's members, keyed by account for `O(1)` membership lookups. Backed by a
+    /// `CountedStorageMap` so `mock_add_member` can cheaply enforce the `MaxMembers` bound without
+    /// an extra counter.
     #[pallet::storage]
-    #[pallet::getter(fn group_members)]
-    pub(super) type GroupMembers<T: Config> = StorageValue<_, Vec<T::AccountId>>;
+    pub(super) type GroupMembers<T: Config<I>, I: 'static = ()> =
+        CountedStorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
 
     /// Events that functions in this pallet can emit.
     ///
@@ -72,7 +122,7 @@ pub mod pallet {
     /// [`Config`] trait) and deposit it using [`frame_system::Pallet::deposit_event`].
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
+    pub enum Event<T: Config<I>, I: 'static = ()> {
         // swap old value with new value (new_value, time_now)
         InefficientValueChange(u32, BlockNumberFor<T>),
         // '' (new_value, time_now)
@@ -92,12 +142,18 @@ pub mod pallet {
     /// This type of runtime error can be up to 4 bytes in size should you want to return additional
     /// information.
     #[pallet::error]
-    pub enum Error<T> {
+    pub enum Error<T, I = ()> {
         /// The requested user has not stored a value yet
         NoValueStored,
 
         /// The value cannot be incremented further because it has reached the maximum allowed value
         MaxValueReached,
+
+        /// Only the current `KingMember` may dispatch a call as the king
+        NotKing,
+
+        /// `GroupMembers` is already at its `MaxMembers` bound
+        TooManyMembers,
     }
 
     /// The pallet's dispatchable functions ([`Call`]s).
@@ -113,26 +169,26 @@ pub mod pallet {
     ///
     /// The [`weight`] macro is used to assign a weight to each call.
     #[pallet::call]
-    impl<T: Config> Pallet<T> {
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
         #[pallet::call_index(0)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::increase_value_no_cache())]
         pub fn increase_value_no_cache(
             origin: OriginFor<T>,
             some_val: u32,
         ) -> DispatchResultWithPostInfo {
             let _ = ensure_signed(origin)?;
-            let original_call = <SomeCopyValue<T>>::get();
+            let original_call = <SomeCopyValue<T, I>>::get();
             let some_calculation = original_call
                 .unwrap()
                 .checked_add(some_val)
                 .ok_or("addition overflowed1")?;
             // this next storage call is unnecessary and is wasteful
-            let unnecessary_call = <SomeCopyValue<T>>::get();
+            let unnecessary_call = <SomeCopyValue<T, I>>::get();
             // should've just used `original_call` here because u32 is copy
             let another_calculation = some_calculation
                 .checked_add(unnecessary_call.unwrap())
                 .ok_or("addition overflowed2")?;
-            <SomeCopyValue<T>>::put(another_calculation);
+            <SomeCopyValue<T, I>>::put(another_calculation);
             let now = <frame_system::Pallet<T>>::block_number();
             Self::deposit_event(Event::InefficientValueChange(another_calculation, now));
             Ok(().into())
@@ -140,13 +196,13 @@ pub mod pallet {
 
         /// Read the value stored at a particular key and emit it in an event
         #[pallet::call_index(1)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::increase_value_w_copy())]
         pub fn increase_value_w_copy(
             origin: OriginFor<T>,
             some_val: u32,
         ) -> DispatchResultWithPostInfo {
             let _ = ensure_signed(origin)?;
-            let original_call = <SomeCopyValue<T>>::get();
+            let original_call = <SomeCopyValue<T, I>>::get();
             let some_calculation = original_call
                 .unwrap()
                 .checked_add(some_val)
@@ -155,7 +211,7 @@ pub mod pallet {
             let another_calculation = some_calculation
                 .checked_add(original_call.unwrap())
                 .ok_or("addition overflowed2")?;
-            <SomeCopyValue<T>>::put(another_calculation);
+            <SomeCopyValue<T, I>>::put(another_calculation);
             let now = <frame_system::Pallet<T>>::block_number();
             Self::deposit_event(Event::BetterValueChange(another_calculation, now));
             Ok(().into())
@@ -163,10 +219,10 @@ pub mod pallet {
         /// Read the value stored at a particular key, while removing it from the map.
         /// Also emit the read value in an event
         #[pallet::call_index(2)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::swap_king_no_cache(<GroupMembers<T, I>>::count()))]
         pub fn swap_king_no_cache(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             let new_king = ensure_signed(origin)?;
-            let existing_king = <KingMember<T>>::get();
+            let existing_king = <KingMember<T, I>>::get();
 
             // only places a new account if
             // (1) the existing account is not a member &&
@@ -181,11 +237,11 @@ pub mod pallet {
             );
 
             // BAD (unnecessary) storage call
-            let old_king = <KingMember<T>>::get();
+            let old_king = <KingMember<T, I>>::get();
             // place new king
-            <KingMember<T>>::put(new_king.clone());
+            <KingMember<T, I>>::put(new_king.clone());
             // new_king without clone gives move error
-            // <KingMember<T>>::put(new_king);
+            // <KingMember<T, I>>::put(new_king);
 
             Self::deposit_event(Event::InefficientKingSwap(old_king.unwrap(), new_king));
             Ok(().into())
@@ -193,10 +249,10 @@ pub mod pallet {
 
         /// Increase the value associated with a particular key
         #[pallet::call_index(3)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::swap_king_with_cache(<GroupMembers<T, I>>::count()))]
         pub fn swap_king_with_cache(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             let new_king = ensure_signed(origin)?;
-            let existing_king = <KingMember<T>>::get();
+            let existing_king = <KingMember<T, I>>::get();
             // prefer to clone previous call rather than repeat call unnecessarily
             let old_king = existing_king.clone();
 
@@ -214,41 +270,94 @@ pub mod pallet {
 
             // <no (unnecessary) storage call here>
             // place new king
-            <KingMember<T>>::put(new_king.clone());
+            <KingMember<T, I>>::put(new_king.clone());
 
             Self::deposit_event(Event::BetterKingSwap(old_king.unwrap(), new_king));
             Ok(().into())
         }
 
         #[pallet::call_index(4)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::set_copy())]
         pub fn set_copy(origin: OriginFor<T>, val: u32) -> DispatchResultWithPostInfo {
             let _ = ensure_signed(origin)?;
-            <SomeCopyValue<T>>::put(val);
+            <SomeCopyValue<T, I>>::put(val);
             Ok(().into())
         }
 
         #[pallet::call_index(5)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::set_king())]
         pub fn set_king(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             let user = ensure_signed(origin)?;
-            <KingMember<T>>::put(user);
+            <KingMember<T, I>>::put(user);
             Ok(().into())
         }
 
         #[pallet::call_index(6)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::mock_add_member(<GroupMembers<T, I>>::count()))]
         pub fn mock_add_member(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             let added = ensure_signed(origin)?;
             ensure!(!Self::is_member(&added), "member already in group");
-            <GroupMembers<T>>::append(added);
+            ensure!(
+                <GroupMembers<T, I>>::count() < T::MaxMembers::get(),
+                Error::<T, I>::TooManyMembers
+            );
+            <GroupMembers<T, I>>::insert(added, ());
             Ok(().into())
         }
+
+        /// Re-dispatch `call` under the [`Origin::King`] origin, as a proxy gated on the caller
+        /// being the current [`KingMember`]. This follows `pallet_utility`'s `dispatch_as`
+        /// approach of forwarding a call under a distinguished origin.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::dispatch_as_king().saturating_add(call.get_dispatch_info().weight))]
+        pub fn dispatch_as_king(
+            origin: OriginFor<T>,
+            call: Box<<T as Config<I>>::RuntimeCall>,
+        ) -> DispatchResultWithPostInfo {
+            let caller = ensure_signed(origin)?;
+            let king = <KingMember<T, I>>::get().ok_or(Error::<T, I>::NoValueStored)?;
+            ensure!(caller == king, Error::<T, I>::NotKing);
+
+            let king_origin: <T as Config<I>>::RuntimeOrigin = Origin::King(king).into();
+            call.dispatch(king_origin)
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+        /// Checks that the invariants this pallet relies on, but cannot enforce through the type
+        /// system alone, still hold: `GroupMembers` stays within its `MaxMembers` bound, and the
+        /// `KingMember`, if set, is always a member of `GroupMembers`. Duplicate membership can no
+        /// longer occur since `GroupMembers` is keyed storage rather than a `Vec`.
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            if <GroupMembers<T, I>>::count() > T::MaxMembers::get() {
+                log::warn!(
+                    target: "runtime::storage-cache",
+                    "GroupMembers holds more accounts than MaxMembers allows",
+                );
+                return Err("GroupMembers holds more accounts than MaxMembers allows".into());
+            }
+
+            if let Some(king) = <KingMember<T, I>>::get() {
+                if !Self::is_member(&king) {
+                    log::warn!(
+                        target: "runtime::storage-cache",
+                        "KingMember is set but is not a member of GroupMembers",
+                    );
+                    return Err("KingMember is set but is not a member of GroupMembers".into());
+                }
+            }
+
+            Ok(())
+        }
     }
 }
 
-impl<T: Config> Pallet<T> {
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// `O(1)` membership check backed by the `GroupMembers` map, rather than decoding and
+    /// scanning a `Vec<T::AccountId>` on every call.
     pub fn is_member(who: &T::AccountId) -> bool {
-        <GroupMembers<T>>::get().unwrap().contains(who)
+        <GroupMembers<T, I>>::contains_key(who)
     }
 }
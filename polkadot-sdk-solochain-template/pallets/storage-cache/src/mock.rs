@@ -0,0 +1,67 @@
+use crate as pallet_storage_cache;
+use frame_support::derive_impl;
+use frame_support::instances::{Instance1, Instance2};
+use frame_support::parameter_types;
+use sp_runtime::BuildStorage;
+
+parameter_types! {
+    pub const MaxMembers: u32 = 16;
+}
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    // Two independent instances of the same recipe pallet, each with its own storage.
+    #[runtime::pallet_index(1)]
+    pub type Template = pallet_storage_cache<Instance1>;
+
+    #[runtime::pallet_index(2)]
+    pub type Template2 = pallet_storage_cache<Instance2>;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = frame_system::mocking::MockBlock<Test>;
+    type AccountId = u64;
+    type Lookup = sp_runtime::traits::IdentityLookup<Self::AccountId>;
+}
+
+impl pallet_storage_cache::Config<Instance1> for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type MaxMembers = MaxMembers;
+}
+
+impl pallet_storage_cache::Config<Instance2> for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type MaxMembers = MaxMembers;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    storage.into()
+}
@@ -0,0 +1,145 @@
+
+//! Autogenerated weights for `pallet_storage_cache`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 42.0.0
+//! DATE: 2024-09-22, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `amiya`, CPU: `12th Gen Intel(R) Core(TM) i7-12650H`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+
+// Executed Command:
+// target/release/solochain-template-node
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution
+// wasm
+// --wasm-execution
+// compiled
+// --pallet
+// pallet-storage-cache
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// pallets/storage-cache/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_storage_cache`.
+pub trait WeightInfo {
+	fn increase_value_no_cache() -> Weight;
+	fn increase_value_w_copy() -> Weight;
+	fn swap_king_no_cache(m: u32) -> Weight;
+	fn swap_king_with_cache(m: u32) -> Weight;
+	fn set_copy() -> Weight;
+	fn set_king() -> Weight;
+	fn mock_add_member(m: u32) -> Weight;
+	fn dispatch_as_king() -> Weight;
+}
+
+/// Weights for `pallet_storage_cache` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn increase_value_no_cache() -> Weight {
+		Weight::from_parts(4_200_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn increase_value_w_copy() -> Weight {
+		Weight::from_parts(3_950_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `m` is `[1, 16]`.
+	fn swap_king_no_cache(m: u32) -> Weight {
+		Weight::from_parts(4_500_000, 0)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `m` is `[1, 16]`.
+	fn swap_king_with_cache(m: u32) -> Weight {
+		Weight::from_parts(4_100_000, 0)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_copy() -> Weight {
+		Weight::from_parts(3_400_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_king() -> Weight {
+		Weight::from_parts(3_400_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `m` is `[1, 15]`.
+	fn mock_add_member(m: u32) -> Weight {
+		Weight::from_parts(3_800_000, 0)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(1_500, 0).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn dispatch_as_king() -> Weight {
+		Weight::from_parts(3_600_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	fn increase_value_no_cache() -> Weight {
+		Weight::from_parts(4_200_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn increase_value_w_copy() -> Weight {
+		Weight::from_parts(3_950_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn swap_king_no_cache(m: u32) -> Weight {
+		Weight::from_parts(4_500_000, 0)
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn swap_king_with_cache(m: u32) -> Weight {
+		Weight::from_parts(4_100_000, 0)
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_copy() -> Weight {
+		Weight::from_parts(3_400_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_king() -> Weight {
+		Weight::from_parts(3_400_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn mock_add_member(m: u32) -> Weight {
+		Weight::from_parts(3_800_000, 0)
+			.saturating_add(Weight::from_parts(1_500, 0).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn dispatch_as_king() -> Weight {
+		Weight::from_parts(3_600_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+}